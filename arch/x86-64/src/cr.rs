@@ -0,0 +1,59 @@
+use bitfield_struct::bitfield;
+
+/// Raw value of `CR0` register.
+///
+/// See CR0 Register section on AMD64 Architecture Programmer's Manual Volume 2 for more details.
+#[bitfield(u64)]
+pub struct Cr0 {
+    pub pe: bool,
+    pub mp: bool,
+    pub em: bool,
+    pub ts: bool,
+    pub et: bool,
+    pub ne: bool,
+    #[bits(10)]
+    __: u16,
+    pub wp: bool,
+    __: bool,
+    pub am: bool,
+    #[bits(10)]
+    __: u16,
+    pub nw: bool,
+    pub cd: bool,
+    pub pg: bool,
+    __: u32,
+}
+
+/// Raw value of `CR4` register.
+///
+/// See CR4 Register section on AMD64 Architecture Programmer's Manual Volume 2 for more details.
+#[bitfield(u64)]
+pub struct Cr4 {
+    pub vme: bool,
+    pub pvi: bool,
+    pub tsd: bool,
+    pub de: bool,
+    pub pse: bool,
+    pub pae: bool,
+    pub mce: bool,
+    pub pge: bool,
+    pub pce: bool,
+    pub osfxsr: bool,
+    pub osxmmexcpt: bool,
+    pub umip: bool,
+    pub la57: bool,
+    pub vmxe: bool,
+    pub smxe: bool,
+    __: bool,
+    pub fsgsbase: bool,
+    pub pcide: bool,
+    pub osxsave: bool,
+    pub kl: bool,
+    pub smep: bool,
+    pub smap: bool,
+    pub pke: bool,
+    pub cet: bool,
+    pub pks: bool,
+    #[bits(39)]
+    __: u64,
+}
@@ -1,9 +1,12 @@
 #![no_std]
+pub use self::cr::*;
 pub use self::msr::*;
 pub use self::segment::*;
 
 use bitfield_struct::bitfield;
+use core::fmt::{Display, Formatter};
 
+mod cr;
 mod msr;
 mod segment;
 
@@ -37,3 +40,32 @@ pub struct Rflags {
     #[bits(42)]
     __: u64,
 }
+
+impl Display for Rflags {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let flags = [
+            (self.cf(), "CF"),
+            (self.pf(), "PF"),
+            (self.af(), "AF"),
+            (self.zf(), "ZF"),
+            (self.sf(), "SF"),
+            (self.tf(), "TF"),
+            (self.r#if(), "IF"),
+            (self.df(), "DF"),
+            (self.of(), "OF"),
+            (self.nt(), "NT"),
+            (self.rf(), "RF"),
+            (self.vm(), "VM"),
+            (self.ac(), "AC"),
+            (self.vif(), "VIF"),
+            (self.vip(), "VIP"),
+            (self.id(), "ID"),
+        ];
+
+        for (_, n) in flags.into_iter().filter(|v| v.0) {
+            write!(f, "{n} ")?;
+        }
+
+        write!(f, "IOPL={}", self.iopl())
+    }
+}
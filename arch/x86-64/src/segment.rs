@@ -14,6 +14,73 @@ pub struct SegmentSelector {
     pub si: u16,
 }
 
+/// Raw value of a Segment Descriptor.
+///
+/// See Legacy Segment Descriptors section on AMD64 Architecture Programmer's Manual Volume 2 for
+/// more details.
+#[bitfield(u64)]
+pub struct SegmentDescriptor {
+    pub limit1: u16,
+    #[bits(24)]
+    pub base1: u32,
+    #[bits(4)]
+    pub ty: u8,
+    pub s: bool,
+    #[bits(2)]
+    pub dpl: Dpl,
+    pub p: bool,
+    #[bits(4)]
+    pub limit2: u8,
+    pub avl: bool,
+    pub l: bool,
+    pub db: bool,
+    pub g: bool,
+    pub base2: u8,
+}
+
+impl SegmentDescriptor {
+    /// Returns a present 64-bit code segment. Although x86-64 specs from AMD ignore most of the
+    /// fields on 64-bit mode but Intel CPU require the type and S flag to be set.
+    pub const fn code64(dpl: Dpl) -> Self {
+        Self::new()
+            .with_ty(0b1000)
+            .with_s(true)
+            .with_dpl(dpl)
+            .with_p(true)
+            .with_l(true)
+    }
+
+    /// Returns a present writable data segment. See [`Self::code64()`] for the reason why the type
+    /// and S flag are set.
+    pub const fn data(dpl: Dpl) -> Self {
+        Self::new()
+            .with_ty(0b0010)
+            .with_s(true)
+            .with_dpl(dpl)
+            .with_p(true)
+    }
+
+    pub const fn base(&self) -> u32 {
+        self.base1() | ((self.base2() as u32) << 24)
+    }
+
+    pub const fn with_base(self, v: u32) -> Self {
+        self.with_base1(v & 0xFFFFFF).with_base2((v >> 24) as u8)
+    }
+
+    pub const fn limit(&self) -> u32 {
+        self.limit1() as u32 | ((self.limit2() as u32) << 16)
+    }
+
+    /// # Panics
+    /// If `v` is larger than 20 bits.
+    pub const fn with_limit(self, v: u32) -> Self {
+        assert!(v <= 0xFFFFF);
+
+        self.with_limit1(v as u16).with_limit2((v >> 16) as u8)
+    }
+}
+
 /// Raw value of Descriptor Privilege-Level field.
 #[repr(u8)]
 #[derive(Debug, Clone, Copy)]
@@ -65,3 +132,34 @@ impl Ti {
         self as _
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn segment_descriptor() {
+        assert_eq!(
+            SegmentDescriptor::code64(Dpl::Ring0).into_bits(),
+            0x0020980000000000
+        );
+        assert_eq!(
+            SegmentDescriptor::code64(Dpl::Ring3).into_bits(),
+            0x0020F80000000000
+        );
+        assert_eq!(
+            SegmentDescriptor::data(Dpl::Ring0).into_bits(),
+            0x0000920000000000
+        );
+
+        let d = SegmentDescriptor::data(Dpl::Ring0)
+            .with_base(0x12345678)
+            .with_limit(0xABCDE)
+            .with_g(true)
+            .with_db(true);
+
+        assert_eq!(d.into_bits(), 0x12CA92345678BCDE);
+        assert_eq!(d.base(), 0x12345678);
+        assert_eq!(d.limit(), 0xABCDE);
+    }
+}
@@ -8,7 +8,7 @@ use libc::ioctl;
 use std::mem::MaybeUninit;
 use std::os::fd::{AsRawFd, OwnedFd};
 use thiserror::Error;
-use x86_64::{Efer, Rflags};
+use x86_64::{Cr0, Cr4, Efer, Rflags};
 
 /// Implementation of [`CpuStates`] for KVM.
 pub struct KvmStates<'a> {
@@ -150,8 +150,8 @@ impl CpuStates for KvmStates<'_> {
         self.gdirty = true;
     }
 
-    fn set_cr0(&mut self, v: usize) {
-        self.sregs.cr0 = v.try_into().unwrap();
+    fn set_cr0(&mut self, v: Cr0) {
+        self.sregs.cr0 = v.into_bits();
         self.sdirty = true;
     }
 
@@ -160,8 +160,8 @@ impl CpuStates for KvmStates<'_> {
         self.sdirty = true;
     }
 
-    fn set_cr4(&mut self, v: usize) {
-        self.sregs.cr4 = v.try_into().unwrap();
+    fn set_cr4(&mut self, v: Cr4) {
+        self.sregs.cr4 = v.into_bits();
         self.sdirty = true;
     }
 
@@ -14,7 +14,7 @@ use windows_sys::Win32::System::Hypervisor::{
     WHvX64RegisterSs, WHV_PARTITION_HANDLE, WHV_REGISTER_NAME, WHV_REGISTER_VALUE,
     WHV_RUN_VP_EXIT_CONTEXT,
 };
-use x86_64::{Efer, Rflags};
+use x86_64::{Cr0, Cr4, Efer, Rflags};
 
 const REGISTERS: usize = 12;
 
@@ -228,8 +228,8 @@ impl<'a, 'b> CpuStates for WhpStates<'a, 'b> {
         self.dirty = true;
     }
 
-    fn set_cr0(&mut self, v: usize) {
-        self.values[2].Reg64 = v.try_into().unwrap();
+    fn set_cr0(&mut self, v: Cr0) {
+        self.values[2].Reg64 = v.into_bits();
         self.dirty = true;
     }
 
@@ -238,8 +238,8 @@ impl<'a, 'b> CpuStates for WhpStates<'a, 'b> {
         self.dirty = true;
     }
 
-    fn set_cr4(&mut self, v: usize) {
-        self.values[4].Reg64 = v.try_into().unwrap();
+    fn set_cr4(&mut self, v: Cr4) {
+        self.values[4].Reg64 = v.into_bits();
         self.dirty = true;
     }
 
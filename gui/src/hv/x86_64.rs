@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use std::error::Error;
-use x86_64::{Efer, Rflags};
+use x86_64::{Cr0, Cr4, Efer, Rflags};
 
 /// States of a CPU.
 pub trait CpuStates {
@@ -28,9 +28,9 @@ pub trait CpuStates {
     fn get_rip(&mut self) -> Result<usize, Self::Err>;
     fn set_rip(&mut self, v: usize);
 
-    fn set_cr0(&mut self, v: usize);
+    fn set_cr0(&mut self, v: Cr0);
     fn set_cr3(&mut self, v: usize);
-    fn set_cr4(&mut self, v: usize);
+    fn set_cr4(&mut self, v: Cr4);
     fn get_rflags(&mut self) -> Result<Rflags, Self::Err>;
     fn set_efer(&mut self, v: Efer);
     fn get_cs(&mut self) -> Result<u16, Self::Err>;
@@ -412,13 +412,17 @@ fn extract_partition(
         let mut path = root.to_path_buf();
 
         for com in name.split('/').skip(1) {
-            if com.is_empty() || com.contains('\\') {
+            if !is_safe_component(com) {
                 return Err(PartitionError::UnexpectedFile(name));
             }
 
             path.push(com);
         }
 
+        if !path.starts_with(&root) {
+            return Err(PartitionError::UnexpectedFile(name));
+        }
+
         // Extract item.
         match data {
             Some(mut data) => {
@@ -455,6 +459,45 @@ fn extract_partition(
     Ok(())
 }
 
+/// Returns `true` if `com` can be used as a single component of a host path without escaping the
+/// parent directory or referring to a device.
+///
+/// We reject the unsafe name instead of escaping it because the extracted files are looked up by
+/// their original name later.
+fn is_safe_component(com: &str) -> bool {
+    if com.is_empty() || com == "." || com == ".." || com.contains(['/', '\\', '\0']) {
+        return false;
+    }
+
+    // Windows will silently strip trailing dots and spaces, rejects some characters (':'
+    // also selects a drive or a stream) and the reserved device names are reserved regardless of
+    // the extension.
+    #[cfg(windows)]
+    {
+        if com.ends_with(['.', ' '])
+            || com.contains([':', '<', '>', '"', '|', '?', '*'])
+            || com.bytes().any(|b| b < 0x20)
+        {
+            return false;
+        }
+
+        let stem = com.split('.').next().unwrap().to_ascii_uppercase();
+        let reserved = match stem.as_str() {
+            "CON" | "PRN" | "AUX" | "NUL" => true,
+            v if v.len() == 4 && (v.starts_with("COM") || v.starts_with("LPT")) => {
+                matches!(v.as_bytes()[3], b'1'..=b'9')
+            }
+            _ => false,
+        };
+
+        if reserved {
+            return false;
+        }
+    }
+
+    true
+}
+
 /// Represents an error when [`extract_firmware_dump()`] fails.
 #[derive(Debug, Error)]
 enum FirmwareError {
@@ -523,3 +566,29 @@ pub enum SetupError {
     #[error("couldn't show setup wizard")]
     ShowWindow(#[source] PlatformError),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn safe_component() {
+        assert!(is_safe_component("system"));
+        assert!(is_safe_component("eboot.bin"));
+        assert!(!is_safe_component(""));
+        assert!(!is_safe_component("."));
+        assert!(!is_safe_component(".."));
+        assert!(!is_safe_component("a/b"));
+        assert!(!is_safe_component("a\\b"));
+        assert!(!is_safe_component("a\0b"));
+
+        // The following names are only unsafe on Windows.
+        assert_eq!(is_safe_component("C:"), !cfg!(windows));
+        assert_eq!(is_safe_component("CON.txt"), !cfg!(windows));
+        assert_eq!(is_safe_component("lpt1"), !cfg!(windows));
+        assert_eq!(is_safe_component("file."), !cfg!(windows));
+        assert_eq!(is_safe_component("file "), !cfg!(windows));
+        assert_eq!(is_safe_component("a?b"), !cfg!(windows));
+        assert_eq!(is_safe_component("a\x01b"), !cfg!(windows));
+    }
+}
@@ -715,7 +715,34 @@ impl<H: Hypervisor> MultiThreadBase for Vmm<H> {
     }
 
     fn write_addrs(&mut self, start_addr: u64, data: &[u8], tid: Tid) -> TargetResult<(), Self> {
-        todo!()
+        let Some(len) = NonZero::new(data.len()) else {
+            return Ok(());
+        };
+
+        // Translate virtual address to physical address.
+        let cpu = self
+            .cpus
+            .get_mut(&(tid.get() - 1))
+            .ok_or(TargetError::Errno(Self::GDB_ENOENT))?;
+
+        let addr = cpu
+            .debug
+            .as_mut()
+            .unwrap()
+            .translate_address(start_addr.try_into().unwrap())
+            .ok_or(TargetError::Errno(Self::GDB_ENOENT))?;
+
+        // Write data.
+        let mut dst = self
+            .hv
+            .ram()
+            .lock(addr, len)
+            .ok_or(TargetError::Errno(Self::GDB_EFAULT))?;
+
+        unsafe { std::slice::from_raw_parts_mut(dst.as_mut_ptr(), dst.len().get()) }
+            .copy_from_slice(data);
+
+        Ok(())
     }
 
     fn is_thread_alive(&mut self, tid: Tid) -> Result<bool, Self::Error> {
@@ -10,7 +10,7 @@ use gdbstub::target::ext::breakpoints::{
 use gdbstub::target::{TargetError, TargetResult};
 use gdbstub_arch::x86::X86_64_SSE;
 use std::num::NonZero;
-use x86_64::Efer;
+use x86_64::{Cr0, Cr4, Efer};
 
 pub type GdbRegs = gdbstub_arch::x86::reg::X86_64CoreRegs;
 
@@ -32,22 +32,13 @@ pub fn setup_main_cpu(
     states.set_cr3(map.page_table);
 
     // Set CR4.
-    let mut cr4 = 0;
-
-    cr4 |= 0x20; // Physical-address extensions (PAE).
-
-    states.set_cr4(cr4);
+    states.set_cr4(Cr4::new().with_pae(true));
 
     // Set EFER to enable long mode with 64-bit.
     states.set_efer(Efer::new().with_lme(true).with_lma(true));
 
     // Set CR0.
-    let mut cr0 = 0;
-
-    cr0 |= 0x00000001; // Protected Mode Enable (PE).
-    cr0 |= 0x80000000; // Paging (PG).
-
-    states.set_cr0(cr0);
+    states.set_cr0(Cr0::new().with_pe(true).with_pg(true));
 
     // Set CS to 64-bit mode with ring 0. Although x86-64 specs from AMD ignore the Code/Data flag
     // on 64-bit mode but Intel CPU violate this spec so we need to enable it.
@@ -1,6 +1,7 @@
 use crate::config::boot_env;
 use anstyle::{AnsiColor, Color, Style};
 use core::fmt::{Display, Formatter};
+use core::sync::atomic::{AtomicU8, Ordering};
 use obconf::{BootEnv, ConsoleType};
 
 mod vm;
@@ -68,6 +69,21 @@ pub fn error(file: &str, line: u32, msg: impl Display) {
     )
 }
 
+static MIN_LEVEL: AtomicU8 = AtomicU8::new(0);
+
+/// Set the minimum level of the log to be written. Any logs below this level will be discarded
+/// without formatting.
+///
+/// # Context safety
+/// This function does not require a CPU context.
+///
+/// # Interrupt safety
+/// This function is interrupt safe.
+#[allow(dead_code)] // TODO: Remove this once we have a boot option for it.
+pub fn set_min_level(v: ConsoleType) {
+    MIN_LEVEL.store(v.into(), Ordering::Relaxed);
+}
+
 /// # Context safety
 /// This function does not require a CPU context as long as [`Display`] implementation on `msg` does
 /// not.
@@ -76,6 +92,10 @@ pub fn error(file: &str, line: u32, msg: impl Display) {
 /// This function is interupt safe as long as [`Display`] implementation on `msg` are interupt safe
 /// (e.g. no heap allocation).
 fn print(ty: ConsoleType, msg: impl Display) {
+    if u8::from(ty) < MIN_LEVEL.load(Ordering::Relaxed) {
+        return;
+    }
+
     match boot_env() {
         BootEnv::Vm(env) => self::vm::print(env, ty, msg),
     }
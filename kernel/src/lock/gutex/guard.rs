@@ -1,6 +1,116 @@
 use super::GroupGuard;
 use core::fmt::{Display, Formatter};
+use core::mem::ManuallyDrop;
 use core::ops::{Deref, DerefMut};
+use core::ptr::read;
+
+/// RAII structure used to release the shared read access of a lock when dropped.
+pub struct GutexRead<'a, T> {
+    #[allow(dead_code)] // active and value fields is protected by this lock.
+    lock: GroupGuard<'a>,
+    active: *mut usize,
+    value: *const T,
+}
+
+impl<'a, T> GutexRead<'a, T> {
+    /// # Safety
+    /// `active` and `value` must be protected by `lock`.
+    pub(super) unsafe fn new(lock: GroupGuard<'a>, active: *mut usize, value: *const T) -> Self {
+        Self {
+            lock,
+            active,
+            value,
+        }
+    }
+
+    /// Narrows this guard to a component of the locked data. The lock is still held until the
+    /// returned guard is dropped.
+    #[allow(dead_code)] // TODO: Remove this once we have a reader.
+    pub fn map<U>(self, f: impl FnOnce(&T) -> &U) -> GutexMappedRead<'a, U> {
+        let r = ManuallyDrop::new(self);
+        let value = f(unsafe { &*r.value }) as *const U;
+
+        // SAFETY: This is safe because we don't run the destructor of the original guard so the
+        // lock will be moved to the new guard as-is.
+        unsafe { GutexMappedRead::new(read(&r.lock), r.active, value) }
+    }
+}
+
+impl<T> Drop for GutexRead<'_, T> {
+    fn drop(&mut self) {
+        unsafe { *self.active -= 1 };
+    }
+}
+
+impl<T> Deref for GutexRead<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.value }
+    }
+}
+
+impl<T: Display> Display for GutexRead<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.deref().fmt(f)
+    }
+}
+
+unsafe impl<T: Sync> Sync for GutexRead<'_, T> {}
+
+/// RAII structure used to release the shared read access of a lock when dropped. This guard point
+/// to a component of the locked data.
+pub struct GutexMappedRead<'a, T> {
+    #[allow(dead_code)] // active and value fields is protected by this lock.
+    lock: GroupGuard<'a>,
+    active: *mut usize,
+    value: *const T,
+}
+
+impl<'a, T> GutexMappedRead<'a, T> {
+    /// # Safety
+    /// `active` and `value` must be protected by `lock`.
+    unsafe fn new(lock: GroupGuard<'a>, active: *mut usize, value: *const T) -> Self {
+        Self {
+            lock,
+            active,
+            value,
+        }
+    }
+
+    /// Narrows this guard further.
+    #[allow(dead_code)] // TODO: Remove this once we have a reader.
+    pub fn map<U>(self, f: impl FnOnce(&T) -> &U) -> GutexMappedRead<'a, U> {
+        let r = ManuallyDrop::new(self);
+        let value = f(unsafe { &*r.value }) as *const U;
+
+        // SAFETY: This is safe because we don't run the destructor of the original guard so the
+        // lock will be moved to the new guard as-is.
+        unsafe { GutexMappedRead::new(read(&r.lock), r.active, value) }
+    }
+}
+
+impl<T> Drop for GutexMappedRead<'_, T> {
+    fn drop(&mut self) {
+        unsafe { *self.active -= 1 };
+    }
+}
+
+impl<T> Deref for GutexMappedRead<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.value }
+    }
+}
+
+impl<T: Display> Display for GutexMappedRead<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.deref().fmt(f)
+    }
+}
+
+unsafe impl<T: Sync> Sync for GutexMappedRead<'_, T> {}
 
 /// RAII structure used to release the exclusive write access of a lock when dropped.
 pub struct GutexWrite<'a, T> {
@@ -20,6 +130,24 @@ impl<'a, T> GutexWrite<'a, T> {
             lock,
         }
     }
+
+    /// Turns this write access into a read access without releasing the group lock. No other
+    /// writer can acquire the lock in between.
+    #[allow(dead_code)] // TODO: Remove this once we have a reader.
+    pub fn downgrade(self) -> GutexRead<'a, T> {
+        let w = ManuallyDrop::new(self);
+
+        // SAFETY: This is safe because we don't run the destructor of the original guard so the
+        // lock will be moved to the new guard as-is.
+        unsafe {
+            let lock = read(&w.lock);
+            let active = w.active;
+
+            *active = 1;
+
+            GutexRead::new(lock, active, w.value)
+        }
+    }
 }
 
 impl<T> Drop for GutexWrite<'_, T> {
@@ -55,19 +55,46 @@ pub struct Gutex<T> {
 }
 
 impl<T> Gutex<T> {
+    /// # Panics
+    /// If there are an active writer.
+    #[allow(dead_code)] // TODO: Remove this once we have a reader.
+    pub fn read(&self) -> GutexRead<T> {
+        // Check if there are an active writer.
+        let lock = self.group.lock();
+        let active = self.active.get();
+
+        // SAFETY: This is safe because we own the lock that protect both active and value.
+        unsafe {
+            if *active == usize::MAX {
+                panic!("attempt to acquire the read lock while there are an active writer");
+            }
+
+            *active += 1;
+
+            GutexRead::new(lock, active, self.value.get())
+        }
+    }
+
     /// # Panics
     /// If there are any active reader or writer.
     pub fn write(&self) -> GutexWrite<T> {
-        // Check if there are active reader or writer.
+        // Check if there are active reader or writer. We own the group lock at this point so any
+        // active guard must belong to the current thread.
         let lock = self.group.lock();
         let active = self.active.get();
 
         // SAFETY: This is safe because we own the lock that protect both active and value.
         unsafe {
-            if *active != 0 {
-                panic!(
-                    "attempt to acquire the write lock while there are an active reader or writer"
-                );
+            match *active {
+                0 => {}
+                usize::MAX => {
+                    panic!(
+                        "attempt to write-lock a Gutex already write-locked by the current thread"
+                    )
+                }
+                _ => panic!(
+                    "attempt to write-lock a Gutex already read-locked by the current thread"
+                ),
             }
 
             *active = usize::MAX;
@@ -4,7 +4,7 @@ use bitfield_struct::bitfield;
 use core::arch::{asm, global_asm};
 use core::mem::{transmute, zeroed};
 use core::ptr::addr_of;
-use x86_64::{Dpl, Efer, Rflags, SegmentSelector, Star};
+use x86_64::{Dpl, Efer, Rflags, SegmentDescriptor, SegmentSelector, Star};
 
 pub const GDT_KERNEL_CS: SegmentSelector = SegmentSelector::new().with_si(3);
 pub const GDT_KERNEL_DS: SegmentSelector = SegmentSelector::new().with_si(4);
@@ -23,16 +23,9 @@ pub unsafe fn setup_main_cpu() -> ContextArgs {
         // 32-bit FS for user.
         SegmentDescriptor::new(),
         // CS for kernel.
-        SegmentDescriptor::new()
-            .with_ty(0b1000) // This required somehow although the docs said it is ignored.
-            .with_s(true) // Same here.
-            .with_p(true)
-            .with_l(true), // 64-bit mode.
+        SegmentDescriptor::code64(Dpl::Ring0),
         // DS for kernel.
-        SegmentDescriptor::new()
-            .with_ty(0b0010) // This required somehow although the docs said it is ignored.
-            .with_s(true) // Same here.
-            .with_p(true),
+        SegmentDescriptor::data(Dpl::Ring0),
         // 32-bit CS for user.
         SegmentDescriptor::new(),
         // DS for user.
@@ -225,30 +218,6 @@ struct Gdtr {
     addr: *const SegmentDescriptor,
 }
 
-/// Raw value of a Segment Descriptor.
-///
-/// See Legacy Segment Descriptors section on AMD64 Architecture Programmer's Manual Volume 2 for
-/// more details.
-#[bitfield(u64)]
-struct SegmentDescriptor {
-    limit1: u16,
-    #[bits(24)]
-    base1: u32,
-    #[bits(4)]
-    ty: u8,
-    s: bool,
-    #[bits(2)]
-    dpl: Dpl,
-    p: bool,
-    #[bits(4)]
-    limit2: u8,
-    avl: bool,
-    l: bool,
-    db: bool,
-    g: bool,
-    base2: u8,
-}
-
 /// Raw value of a TSS descriptor.
 ///
 /// See TSS Descriptor section on AMD64 Architecture Programmer's Manual Volume 2 for more details.
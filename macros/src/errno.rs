@@ -1,15 +1,19 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
-use syn::{punctuated::Punctuated, Fields, Index, ItemEnum, Meta, Token, Variant};
+use syn::parse::ParseStream;
+use syn::{Fields, Index, ItemEnum, LitStr, Token, Variant};
 
 pub fn transform(arg: ItemEnum) -> syn::Result<TokenStream> {
     let enum_name = &arg.ident;
+    let mut arms = Vec::with_capacity(arg.variants.len());
+    let mut msgs = Vec::new();
 
-    let arms = arg
-        .variants
-        .iter()
-        .map(|variant| process_variant(variant, enum_name))
-        .collect::<Result<Vec<_>, _>>()?;
+    for variant in arg.variants.iter() {
+        let (arm, msg) = process_variant(variant, enum_name)?;
+
+        arms.push(arm);
+        msgs.extend(msg);
+    }
 
     if arms.is_empty() {
         Ok(quote!(
@@ -18,6 +22,12 @@ pub fn transform(arg: ItemEnum) -> syn::Result<TokenStream> {
                     match *self {}
                 }
             }
+
+            impl #enum_name {
+                pub fn strerror(&self) -> &'static str {
+                    match *self {}
+                }
+            }
         ))
     } else {
         Ok(quote!(
@@ -28,44 +38,57 @@ pub fn transform(arg: ItemEnum) -> syn::Result<TokenStream> {
                     }
                 }
             }
+
+            impl #enum_name {
+                /// Returns the message specified on `#[errno]` or the message of the errno.
+                pub fn strerror(&self) -> &'static str {
+                    #[allow(unreachable_patterns)]
+                    match self {
+                        #(#msgs)*
+                        _ => crate::errno::strerror(Errno::errno(self)),
+                    }
+                }
+            }
         ))
     }
 }
 
-fn process_variant(variant: &Variant, enum_name: &Ident) -> syn::Result<TokenStream> {
+fn process_variant(
+    variant: &Variant,
+    enum_name: &Ident,
+) -> syn::Result<(TokenStream, Option<TokenStream>)> {
     for attr in variant.attrs.iter() {
         if attr.path().is_ident("errno") {
-            let meta = attr
-                .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?
-                .into_iter()
-                .collect::<Vec<_>>();
-
-            match meta.as_slice() {
-                [Meta::Path(path)] => {
-                    let errno = path.get_ident().ok_or_else(|| {
-                        syn::Error::new_spanned(
-                            attr,
-                            "incorrect errno usage. Correct is #[errno(...)]",
-                        )
-                    })?;
-
-                    let variant_name = &variant.ident;
-
-                    let arm = match variant.fields {
-                        Fields::Unit => quote!(#enum_name::#variant_name => #errno,),
-                        Fields::Named(_) => quote!(#enum_name::#variant_name {..} => #errno,),
-                        Fields::Unnamed(_) => quote!(#enum_name::#variant_name (..) => #errno,),
+            let (errno, msg) = attr
+                .parse_args_with(|input: ParseStream| {
+                    let errno: Ident = input.parse()?;
+                    let msg = if input.is_empty() {
+                        None
+                    } else {
+                        input.parse::<Token![,]>()?;
+                        Some(input.parse::<LitStr>()?)
                     };
 
-                    return Ok(arm);
-                }
-                _ => {
-                    return Err(syn::Error::new_spanned(
+                    Ok((errno, msg))
+                })
+                .map_err(|_| {
+                    syn::Error::new_spanned(
                         attr,
-                        "incorrect errno usage. Correct is #[errno(...)]",
-                    ))
-                }
-            }
+                        "incorrect errno usage. Correct is #[errno(...)] or #[errno(..., \"...\")]",
+                    )
+                })?;
+
+            let variant_name = &variant.ident;
+            let pat = match variant.fields {
+                Fields::Unit => quote!(#enum_name::#variant_name),
+                Fields::Named(_) => quote!(#enum_name::#variant_name {..}),
+                Fields::Unnamed(_) => quote!(#enum_name::#variant_name (..)),
+            };
+
+            return Ok((
+                quote!(#pat => #errno,),
+                msg.map(|msg| quote!(#pat => #msg,)),
+            ));
         }
     }
 
@@ -105,7 +128,10 @@ fn process_variant(variant: &Variant, enum_name: &Ident) -> syn::Result<TokenStr
                     // `{ 0usize: e, .. }` which is accepted, but only temporarily
                     let index = Index::from(pos);
 
-                    Ok(quote!(#enum_name::#variant_name { #index: e, .. } => e.errno(),))
+                    Ok((
+                        quote!(#enum_name::#variant_name { #index: e, .. } => e.errno(),),
+                        None,
+                    ))
                 }
                 None => Err(syn::Error::new_spanned(
                     variant,
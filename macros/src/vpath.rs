@@ -10,7 +10,7 @@ pub fn transform(arg: LitStr) -> syn::Result<TokenStream> {
     if arg.is_empty() {
         return Err(Error::new(span, "cannot be an empty string"));
     } else if !arg.starts_with('/') {
-        return Err(Error::new(span, "cannot begin with `/`"));
+        return Err(Error::new(span, "must begin with `/`"));
     } else if arg.ends_with('/') {
         return Err(Error::new(span, "cannot end with `/`"));
     }
@@ -37,5 +37,12 @@ pub fn transform(arg: LitStr) -> syn::Result<TokenStream> {
         sep = i;
     }
 
+    // Check the last component.
+    match &arg[(sep + 1)..] {
+        "." => return Err(Error::new(span, "cannot end with `/.`")),
+        ".." => return Err(Error::new(span, "cannot end with `/..`")),
+        _ => {}
+    }
+
     Ok(quote_spanned!(span=> unsafe { crate::fs::VPath::new_unchecked(#arg) }))
 }
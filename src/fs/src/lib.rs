@@ -1,7 +1,8 @@
 use bitflags::bitflags;
+use filetime::FileTime;
 use serde::{Deserialize, Serialize};
 use std::error::Error;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
 /// Contains medata for a file in the PS4 system.
@@ -23,15 +24,11 @@ pub struct Metadata {
 impl Metadata {
     pub fn create_for<F: Into<PathBuf>>(&self, file: F) -> Result<(), CreateForError> {
         // Create path for metadata.
-        let mut path = file.into();
-        let mut name = match path.file_name() {
-            Some(v) => v.to_os_string(),
+        let path = match Self::path_for(file.into()) {
+            Some(v) => v,
             None => return Err(CreateForError::InvalidFilePath),
         };
 
-        name.push(".obm"); // Let's hope no any games using "obm" as a file extension.
-        path.set_file_name(name);
-
         // Create metadata file.
         let mut file = std::fs::OpenOptions::new();
 
@@ -50,13 +47,82 @@ impl Metadata {
 
         Ok(())
     }
+
+    pub fn load_for<F: Into<PathBuf>>(file: F) -> Result<Self, LoadForError> {
+        // Get path for metadata.
+        let path = match Self::path_for(file.into()) {
+            Some(v) => v,
+            None => return Err(LoadForError::InvalidFilePath),
+        };
+
+        // Open metadata file.
+        let file = match std::fs::File::open(&path) {
+            Ok(v) => v,
+            Err(e) => return Err(LoadForError::OpenMetadataFailed(path, e)),
+        };
+
+        // Read metadata.
+        match serde_yaml::from_reader(file) {
+            Ok(v) => Ok(v),
+            Err(e) => Err(LoadForError::ReadMetadataFailed(path, e.into())),
+        }
+    }
+
+    /// Apply the permissions and timestamps to `file`. On the platform other than Unix only the
+    /// write permission of the owner will be applied.
+    pub fn apply_to(&self, file: &Path) -> Result<(), ApplyError> {
+        // Set timestamps. We need to do this before setting permissions otherwise we might not be
+        // able to modify a read-only file on some platforms.
+        let (atime, mtime) = match (self.atime.try_into(), self.mtime.try_into()) {
+            (Ok(a), Ok(m)) => (
+                FileTime::from_unix_time(a, self.atimensec),
+                FileTime::from_unix_time(m, self.mtimensec),
+            ),
+            _ => return Err(ApplyError::InvalidTime),
+        };
+
+        if let Err(e) = filetime::set_file_times(file, atime, mtime) {
+            return Err(ApplyError::SetTimesFailed(e));
+        }
+
+        // Set permissions.
+        let mut perm = match std::fs::metadata(file) {
+            Ok(v) => v.permissions(),
+            Err(e) => return Err(ApplyError::GetPermissionsFailed(e)),
+        };
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            perm.set_mode((self.mode.bits() & 0o777).into());
+        }
+
+        #[cfg(not(unix))]
+        perm.set_readonly(!self.mode.contains(FileMode::S_IWUSR));
+
+        if let Err(e) = std::fs::set_permissions(file, perm) {
+            return Err(ApplyError::SetPermissionsFailed(e));
+        }
+
+        Ok(())
+    }
+
+    fn path_for(mut file: PathBuf) -> Option<PathBuf> {
+        let mut name = file.file_name()?.to_os_string();
+
+        name.push(".obm"); // Let's hope no any games using "obm" as a file extension.
+        file.set_file_name(name);
+
+        Some(file)
+    }
 }
 
 bitflags! {
     /// Unix mode of a game file.
     ///
     /// The value of this is exactly the same as the value in the PFS.
-    #[derive(Clone, Serialize, Deserialize)]
+    #[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
     #[serde(transparent)]
     #[repr(transparent)]
     pub struct FileMode: u16 {
@@ -69,6 +135,19 @@ bitflags! {
         const S_IXUSR = 0b0000000001000000;
         const S_IWUSR = 0b0000000010000000;
         const S_IRUSR = 0b0000000100000000;
+        const S_IFMT = 0o170000;
+        const S_IFDIR = 0o040000;
+        const S_IFREG = 0o100000;
+        const S_IFLNK = 0o120000;
+    }
+}
+
+impl FileMode {
+    /// Returns the file type bits of this mode. Use this to compare with [`FileMode::S_IFDIR`],
+    /// [`FileMode::S_IFREG`] or [`FileMode::S_IFLNK`] instead of [`FileMode::contains()`] because
+    /// the file type is not a single bit.
+    pub fn file_type(&self) -> Self {
+        Self::from_bits_retain(self.bits() & Self::S_IFMT.bits())
     }
 }
 
@@ -90,3 +169,154 @@ pub enum CreateForError {
     #[error("cannot write {0}")]
     WriteMetadataFailed(PathBuf, #[source] Box<dyn Error>),
 }
+
+/// Errors for [`load_for()`][Metadata::load_for()].
+#[derive(Debug, Error)]
+pub enum LoadForError {
+    #[error("file path is not valid")]
+    InvalidFilePath,
+
+    #[error("cannot open {0}")]
+    OpenMetadataFailed(PathBuf, #[source] std::io::Error),
+
+    #[error("cannot read {0}")]
+    ReadMetadataFailed(PathBuf, #[source] Box<dyn Error>),
+}
+
+/// Errors for [`apply_to()`][Metadata::apply_to()].
+#[derive(Debug, Error)]
+pub enum ApplyError {
+    #[error("timestamp is too large")]
+    InvalidTime,
+
+    #[error("cannot set file times")]
+    SetTimesFailed(#[source] std::io::Error),
+
+    #[error("cannot get file permissions")]
+    GetPermissionsFailed(#[source] std::io::Error),
+
+    #[error("cannot set file permissions")]
+    SetPermissionsFailed(#[source] std::io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{create_dir, remove_dir_all, write};
+
+    #[test]
+    fn load_for() {
+        // Setup test directory.
+        let root = std::env::temp_dir().join(format!("fs-load-for-{}", std::process::id()));
+
+        if root.exists() {
+            remove_dir_all(&root).unwrap();
+        }
+
+        create_dir(&root).unwrap();
+
+        // Write metadata then read it back.
+        let file = root.join("file");
+        let meta = Metadata {
+            mode: FileMode::from(0o444),
+            atime: 1000000000,
+            mtime: 1500000000,
+            ctime: 1600000000,
+            birthtime: 900000000,
+            mtimensec: 1,
+            atimensec: 2,
+            ctimensec: 3,
+            birthnsec: 4,
+            uid: 5,
+            gid: 6,
+        };
+
+        meta.create_for(&file).unwrap();
+
+        let loaded = Metadata::load_for(&file).unwrap();
+
+        assert_eq!(loaded.mode.bits(), meta.mode.bits());
+        assert_eq!(loaded.atime, meta.atime);
+        assert_eq!(loaded.mtime, meta.mtime);
+        assert_eq!(loaded.ctime, meta.ctime);
+        assert_eq!(loaded.birthtime, meta.birthtime);
+        assert_eq!(loaded.mtimensec, meta.mtimensec);
+        assert_eq!(loaded.atimensec, meta.atimensec);
+        assert_eq!(loaded.ctimensec, meta.ctimensec);
+        assert_eq!(loaded.birthnsec, meta.birthnsec);
+        assert_eq!(loaded.uid, meta.uid);
+        assert_eq!(loaded.gid, meta.gid);
+
+        // Load metadata for a file without it.
+        match Metadata::load_for(root.join("missing")) {
+            Err(LoadForError::OpenMetadataFailed(p, _)) => assert_eq!(p, root.join("missing.obm")),
+            _ => panic!("unexpected result when loading missing metadata"),
+        }
+
+        // Clean up.
+        remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn apply_to() {
+        // Setup test directory.
+        let root = std::env::temp_dir().join(format!("fs-apply-to-{}", std::process::id()));
+
+        if root.exists() {
+            remove_dir_all(&root).unwrap();
+        }
+
+        create_dir(&root).unwrap();
+
+        // Apply metadata.
+        let dir = root.join("dir");
+        let file = root.join("file");
+        let meta = |mode: u16| Metadata {
+            mode: FileMode::from(mode),
+            atime: 1000000000,
+            mtime: 1500000000,
+            ctime: 0,
+            birthtime: 0,
+            mtimensec: 500,
+            atimensec: 0,
+            ctimensec: 0,
+            birthnsec: 0,
+            uid: 0,
+            gid: 0,
+        };
+
+        create_dir(&dir).unwrap();
+        write(&file, b"test").unwrap();
+
+        meta(0o040755).apply_to(&dir).unwrap();
+        meta(0o100444).apply_to(&file).unwrap();
+
+        // Applying again should still work on a read-only file.
+        meta(0o100444).apply_to(&file).unwrap();
+
+        // Check metadata.
+        for path in [&dir, &file] {
+            let m = std::fs::metadata(path).unwrap();
+
+            assert_eq!(
+                FileTime::from_last_modification_time(&m),
+                FileTime::from_unix_time(1500000000, 500)
+            );
+        }
+
+        assert!(std::fs::metadata(&file).unwrap().permissions().readonly());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let mode = |p| std::fs::metadata(p).unwrap().permissions().mode() & 0o777;
+
+            assert_eq!(mode(&dir), 0o755);
+            assert_eq!(mode(&file), 0o444);
+        }
+
+        // Clean up.
+        remove_dir_all(&root).unwrap();
+    }
+}
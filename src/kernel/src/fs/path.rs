@@ -113,7 +113,10 @@ impl VPath {
             sep = i;
         }
 
-        true
+        // Check the last component.
+        let com = &data[(sep + 1)..];
+
+        com != "." && com != ".."
     }
 }
 
@@ -265,6 +265,20 @@ impl FileInfo {
         Symbols::new(&self.data[self.symtab..(self.symtab + self.symtabsz)], self)
     }
 
+    /// Returns name of the symbol at `index` without parsing the other symbols. This will return
+    /// [`None`] if `index` is out of bound or the name is not a valid string.
+    pub fn symbol_name(&self, index: usize) -> Option<&str> {
+        // Get the entry.
+        let tab = &self.data[self.symtab..(self.symtab + self.symtabsz)];
+        let off = index.checked_mul(24)?;
+        let sym = tab.get(off..off.checked_add(24)?)?;
+
+        // Lookup name.
+        let name = LE::read_u32(sym);
+
+        self.read_str(name.try_into().unwrap()).ok()
+    }
+
     pub fn buckets(&self) -> &[u32] {
         self.buckets.as_ref()
     }
@@ -4,12 +4,13 @@ pub use library::*;
 pub use module::*;
 pub use program::*;
 pub use reloc::*;
+pub use section::*;
 pub use symbol::*;
 pub use ty::*;
 
 use bitflags::bitflags;
 use byteorder::{ByteOrder, LE};
-use std::io::{Read, Seek, SeekFrom};
+use std::io::{Cursor, Read, Seek, SeekFrom};
 use std::ops::Range;
 use thiserror::Error;
 
@@ -19,6 +20,7 @@ mod library;
 mod module;
 mod program;
 mod reloc;
+mod section;
 mod symbol;
 mod ty;
 
@@ -36,6 +38,7 @@ pub struct Elf<I: Read + Seek> {
     ty: FileType,
     entry_addr: Option<usize>,
     programs: Vec<Program>,
+    sections: Vec<Section>,
     mapping: Range<usize>,
     code: Option<usize>,
     relro: Option<usize>,
@@ -146,6 +149,16 @@ impl<I: Read + Seek> Elf<I> {
         let e_phoff = offset + 0x40; // PS4 is hard-coded this value.
         let e_phnum: usize = LE::read_u16(&hdr[0x38..]).into();
 
+        // Check if the image is large enough for all program headers.
+        let len = match image.seek(SeekFrom::End(0)) {
+            Ok(v) => v,
+            Err(e) => return Err(OpenError::GetImageSizeFailed(e)),
+        };
+
+        if e_phoff + (e_phnum as u64) * 0x38 > len {
+            return Err(OpenError::TooManyProgramHeaders(e_phnum));
+        }
+
         // Seek to first program header.
         match image.seek(SeekFrom::Start(e_phoff)) {
             Ok(v) => {
@@ -174,6 +187,7 @@ impl<I: Read + Seek> Elf<I> {
                 v => Some(v as usize),
             },
             programs: Vec::with_capacity(e_phnum),
+            sections: Vec::new(),
             mapping: Range {
                 start: usize::MAX,
                 end: 0,
@@ -249,24 +263,15 @@ impl<I: Read + Seek> Elf<I> {
             dynoff -= TryInto::<usize>::try_into(dyndata.offset()).unwrap();
 
             // Read PT_SCE_DYNLIBDATA.
-            let mut dyndata = vec![0u8; dyndata.file_size().try_into().unwrap()];
-
-            if let Err(e) = elf.read_program(i, &mut dyndata) {
-                return Err(OpenError::ReadDynDataFailed(e));
-            }
-
-            // Read PT_SCE_COMMENT.
-            let comment = if let Some(i) = elf.comment {
-                let mut buf = vec![0u8; elf.programs[i].file_size().try_into().unwrap()];
+            let dyndata = match elf.read_program_vec(i) {
+                Ok(v) => v,
+                Err(e) => return Err(OpenError::ReadDynDataFailed(e)),
+            };
 
-                if elf.read_program(i, &mut buf).is_err() {
-                    // This is not an error on the PS4.
-                    Vec::new()
-                } else {
-                    buf
-                }
-            } else {
-                Vec::new()
+            // Read PT_SCE_COMMENT. Failing to read it is not an error on the PS4.
+            let comment = match elf.comment {
+                Some(i) => elf.read_program_vec(i).unwrap_or_default(),
+                None => Vec::new(),
             };
 
             // Load info.
@@ -276,6 +281,16 @@ impl<I: Read + Seek> Elf<I> {
             };
         }
 
+        // Load section headers. Only a plain ELF has it since the SELF does not include the section
+        // header table.
+        let e_shoff = LE::read_u64(&hdr[0x28..]);
+        let e_shnum: usize = LE::read_u16(&hdr[0x3c..]).into();
+
+        if elf.self_data.is_none() && e_shoff != 0 && e_shnum != 0 {
+            elf.sections =
+                Self::load_sections(&mut elf.image, &hdr, e_shoff, e_shnum).unwrap_or_default();
+        }
+
         // Check PT_SCE_RELRO.
         if let Some(i) = elf.relro {
             let relro = &elf.programs[i];
@@ -336,6 +351,11 @@ impl<I: Read + Seek> Elf<I> {
         self.programs.as_slice()
     }
 
+    /// Returns an empty slice if the image does not have section header table (e.g. a SELF).
+    pub fn sections(&self) -> &[Section] {
+        self.sections.as_slice()
+    }
+
     pub fn relro(&self) -> Option<usize> {
         self.relro
     }
@@ -399,7 +419,7 @@ impl<I: Read + Seek> Elf<I> {
         match self.image.seek(SeekFrom::Start(offset)) {
             Ok(v) => {
                 if v != offset {
-                    panic!("File is smaller than {offset} bytes.");
+                    return Err(ReadProgramError::InvalidOffset(offset));
                 }
             }
             Err(e) => return Err(ReadProgramError::SeekFailed(offset, e)),
@@ -413,6 +433,105 @@ impl<I: Read + Seek> Elf<I> {
         Ok(())
     }
 
+    /// Same as [`Self::read_program()`] but allocate a buffer with the exact size of the program.
+    pub fn read_program_vec(&mut self, index: usize) -> Result<Vec<u8>, ReadProgramError> {
+        let len = match self.programs.get(index) {
+            Some(v) => v.file_size().try_into().unwrap(),
+            None => return Err(ReadProgramError::InvalidIndex),
+        };
+
+        let mut buf = vec![0u8; len];
+
+        self.read_program(index, &mut buf)?;
+
+        Ok(buf)
+    }
+
+    /// Same as [`Self::read_program_vec()`] but the returned buffer has the size of the program in
+    /// the memory with zero-filled after the file data.
+    pub fn program_data(&mut self, index: usize) -> Result<Vec<u8>, ReadProgramError> {
+        let len = match self.programs.get(index) {
+            Some(v) => v.memory_size().max(v.file_size().try_into().unwrap()),
+            None => return Err(ReadProgramError::InvalidIndex),
+        };
+
+        let mut buf = vec![0u8; len];
+
+        self.read_program(index, &mut buf)?;
+
+        Ok(buf)
+    }
+
+    /// Returns [`None`] if the section header table is not valid. The sections are not required to
+    /// load the programs so we don't want to fail [`Elf::open()`] because of it.
+    fn load_sections(
+        image: &mut I,
+        hdr: &[u8; 64],
+        e_shoff: u64,
+        e_shnum: usize,
+    ) -> Option<Vec<Section>> {
+        if LE::read_u16(&hdr[0x3a..]) != 0x40 {
+            return None;
+        }
+
+        // Check if section headers is within the image.
+        let len = image.seek(SeekFrom::End(0)).ok()?;
+        let size = e_shnum * 0x40;
+
+        if e_shoff.checked_add(size as u64)? > len {
+            return None;
+        }
+
+        // Read section headers.
+        let mut data = vec![0u8; size];
+
+        image.seek(SeekFrom::Start(e_shoff)).ok()?;
+        image.read_exact(&mut data).ok()?;
+
+        // Read section names.
+        let e_shstrndx: usize = LE::read_u16(&hdr[0x3e..]).into();
+        let names = match data.chunks_exact(0x40).nth(e_shstrndx) {
+            Some(h) => {
+                let offset = LE::read_u64(&h[0x18..]);
+                let size = LE::read_u64(&h[0x20..]);
+
+                if offset.checked_add(size)? > len {
+                    return None;
+                }
+
+                let mut names = vec![0u8; size.try_into().ok()?];
+
+                image.seek(SeekFrom::Start(offset)).ok()?;
+                image.read_exact(&mut names).ok()?;
+
+                names
+            }
+            None => Vec::new(),
+        };
+
+        // Load section headers.
+        let mut sections = Vec::with_capacity(e_shnum);
+
+        for h in data.chunks_exact(0x40) {
+            let name: usize = LE::read_u32(h).try_into().unwrap();
+            let name = names
+                .get(name..)
+                .and_then(|v| v.split(|&b| b == 0).next())
+                .map(|v| String::from_utf8_lossy(v).into_owned())
+                .unwrap_or_default();
+
+            sections.push(Section::new(
+                name,
+                SectionType::new(LE::read_u32(&h[0x04..])),
+                LE::read_u64(&h[0x10..]) as usize,
+                LE::read_u64(&h[0x18..]),
+                LE::read_u64(&h[0x20..]),
+            ));
+        }
+
+        Some(sections)
+    }
+
     fn process_mappable(&mut self, index: usize, prog: &Program) -> Result<(), OpenError> {
         // Check offset.
         let ty = prog.ty();
@@ -598,34 +717,53 @@ impl<I: Read + Seek> Elf<I> {
             }
 
             // Check if the target offset inside the associated program.
-            let prog = &self.programs[flags.program()];
+            let prog = match self.programs.get(flags.program()) {
+                Some(v) => v,
+                None => return Err(ReadProgramError::InvalidSegmentProgram(i)),
+            };
+
+            let end = match prog.offset().checked_add(prog.file_size()) {
+                Some(v) => v,
+                None => return Err(ReadProgramError::InvalidSegmentProgram(i)),
+            };
 
-            if offset >= prog.offset() && offset < prog.offset() + prog.file_size() {
+            if offset >= prog.offset() && offset < end {
                 // Check if segment supported.
                 if flags.contains(SelfSegmentFlags::SF_ENCR) {
                     return Err(ReadProgramError::EncryptedSegment(i));
                 }
 
                 if seg.compressed_size != seg.decompressed_size {
-                    panic!("Compressed SELF segment is not supported yet.");
+                    return Err(ReadProgramError::CompressedSegment(i));
                 }
 
                 if seg.decompressed_size != prog.file_size() {
-                    panic!("SELF segment size different than associated program segment is not supported yet.");
+                    return Err(ReadProgramError::SegmentSizeMismatch(i));
                 }
 
                 // Get data offset.
                 let offset = offset - prog.offset();
 
-                if offset + len > seg.decompressed_size {
-                    panic!("Segment block is smaller than the size specified in program header.");
+                match offset.checked_add(len) {
+                    Some(v) if v <= seg.decompressed_size => {}
+                    _ => return Err(ReadProgramError::BlockTooSmall(i)),
                 }
 
-                return Ok(offset + seg.offset);
+                return match seg.offset.checked_add(offset) {
+                    Some(v) => Ok(v),
+                    None => Err(ReadProgramError::InvalidSegmentOffset(i)),
+                };
             }
         }
 
-        panic!("SELF image is corrupted.");
+        Err(ReadProgramError::SegmentNotFound)
+    }
+}
+
+impl Elf<Cursor<Vec<u8>>> {
+    /// Same as [`Elf::open()`] but load from the image that already in the memory.
+    pub fn from_bytes(name: impl Into<String>, data: Vec<u8>) -> Result<Self, OpenError> {
+        Self::open(name, Cursor::new(data))
     }
 }
 
@@ -720,6 +858,12 @@ pub enum OpenError {
     #[error("e_phoff is not valid")]
     InvalidProgramOffset,
 
+    #[error("cannot get image size")]
+    GetImageSizeFailed(#[source] std::io::Error),
+
+    #[error("e_phnum ({0}) exceed the image size")]
+    TooManyProgramHeaders(usize),
+
     #[error("cannot read program headers")]
     ReadProgramHeadersFailed(#[source] std::io::Error),
 
@@ -778,6 +922,27 @@ pub enum ReadProgramError {
     #[error("SELF segment #{0} is encrypted")]
     EncryptedSegment(usize),
 
+    #[error("SELF segment #{0} is compressed")]
+    CompressedSegment(usize),
+
+    #[error("SELF segment #{0} has different size than the associated program")]
+    SegmentSizeMismatch(usize),
+
+    #[error("SELF segment #{0} is smaller than the program")]
+    BlockTooSmall(usize),
+
+    #[error("no SELF segment for the program")]
+    SegmentNotFound,
+
+    #[error("SELF segment #{0} is associated with an invalid program")]
+    InvalidSegmentProgram(usize),
+
+    #[error("SELF segment #{0} has invalid offset")]
+    InvalidSegmentOffset(usize),
+
+    #[error("offset {0:#018x} is beyond the end of file")]
+    InvalidOffset(u64),
+
     #[error("cannot seek to offset {0:#018x}")]
     SeekFailed(u64, #[source] std::io::Error),
 
@@ -0,0 +1,84 @@
+use std::fmt::{Display, Formatter};
+
+/// Contains information for each ELF section.
+#[derive(Debug)]
+pub struct Section {
+    name: String,
+    ty: SectionType,
+    addr: usize,
+    offset: u64,
+    size: u64,
+}
+
+impl Section {
+    pub(super) fn new(name: String, ty: SectionType, addr: usize, offset: u64, size: u64) -> Self {
+        Self {
+            name,
+            ty,
+            addr,
+            offset,
+            size,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    pub fn ty(&self) -> SectionType {
+        self.ty
+    }
+
+    pub fn addr(&self) -> usize {
+        self.addr
+    }
+
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+/// Represents type of an ELF section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SectionType(u32);
+
+impl SectionType {
+    pub const SHT_NULL: SectionType = SectionType(0);
+    pub const SHT_PROGBITS: SectionType = SectionType(1);
+    pub const SHT_SYMTAB: SectionType = SectionType(2);
+    pub const SHT_STRTAB: SectionType = SectionType(3);
+    pub const SHT_RELA: SectionType = SectionType(4);
+    pub const SHT_HASH: SectionType = SectionType(5);
+    pub const SHT_DYNAMIC: SectionType = SectionType(6);
+    pub const SHT_NOTE: SectionType = SectionType(7);
+    pub const SHT_NOBITS: SectionType = SectionType(8);
+    pub const SHT_REL: SectionType = SectionType(9);
+    pub const SHT_DYNSYM: SectionType = SectionType(11);
+
+    pub fn new(v: u32) -> Self {
+        Self(v)
+    }
+}
+
+impl Display for SectionType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::SHT_NULL => f.write_str("SHT_NULL"),
+            Self::SHT_PROGBITS => f.write_str("SHT_PROGBITS"),
+            Self::SHT_SYMTAB => f.write_str("SHT_SYMTAB"),
+            Self::SHT_STRTAB => f.write_str("SHT_STRTAB"),
+            Self::SHT_RELA => f.write_str("SHT_RELA"),
+            Self::SHT_HASH => f.write_str("SHT_HASH"),
+            Self::SHT_DYNAMIC => f.write_str("SHT_DYNAMIC"),
+            Self::SHT_NOTE => f.write_str("SHT_NOTE"),
+            Self::SHT_NOBITS => f.write_str("SHT_NOBITS"),
+            Self::SHT_REL => f.write_str("SHT_REL"),
+            Self::SHT_DYNSYM => f.write_str("SHT_DYNSYM"),
+            t => write!(f, "{:#010x}", t.0),
+        }
+    }
+}
@@ -30,6 +30,7 @@ impl<'a> Iterator for Symbols<'a> {
         let info = self.next[4];
         let shndx = LE::read_u16(&self.next[6..]);
         let value = LE::read_u64(&self.next[8..]);
+        let size = LE::read_u64(&self.next[16..]);
 
         // Load name.
         let name = match self.info.read_str(name.try_into().unwrap()) {
@@ -45,6 +46,7 @@ impl<'a> Iterator for Symbols<'a> {
             info,
             shndx,
             value: value.try_into().unwrap(),
+            size: size.try_into().unwrap(),
         }))
     }
 }
@@ -56,6 +58,7 @@ pub struct Symbol {
     info: u8,
     shndx: u16,
     value: usize,
+    size: usize,
 }
 
 impl Symbol {
@@ -117,6 +120,10 @@ impl Symbol {
     pub fn value(&self) -> usize {
         self.value
     }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
 }
 
 /// Represents an error when reading `Elf64_Sym` is failed.
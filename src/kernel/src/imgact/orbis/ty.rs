@@ -14,6 +14,24 @@ impl FileType {
     pub fn new(v: u16) -> Self {
         Self(v)
     }
+
+    /// Returns `true` if this is `ET_SCE_DYNEXEC`.
+    pub fn is_dynamic_exec(self) -> bool {
+        self == Self::ET_SCE_DYNEXEC
+    }
+
+    /// Returns `true` if this is `ET_SCE_DYNAMIC`.
+    pub fn is_shared_object(self) -> bool {
+        self == Self::ET_SCE_DYNAMIC
+    }
+
+    /// Returns `true` if this is `ET_EXEC`, `ET_SCE_EXEC` or `ET_SCE_REPLAY_EXEC`.
+    pub fn is_static_exec(self) -> bool {
+        matches!(
+            self,
+            Self::ET_EXEC | Self::ET_SCE_EXEC | Self::ET_SCE_REPLAY_EXEC
+        )
+    }
 }
 
 impl Display for FileType {
@@ -30,7 +30,7 @@ use crate::time::TimeManager;
 use crate::ucred::{AuthAttrs, AuthCaps, AuthInfo, AuthPaid, Gid, Ucred, Uid};
 use crate::umtx::UmtxManager;
 use crate::vm::VmMgr;
-use llt::{OsThread, SpawnError};
+use llt::SpawnError;
 use macros::vpath;
 use param::Param;
 use std::error::Error;
@@ -493,7 +493,7 @@ fn run(args: Args) -> Result<(), KernelError> {
     }
 
     // Wait for main thread to exit. This should never return.
-    join_thread(main).map_err(KernelError::FailedToJoinMainThread)?;
+    unsafe { llt::join(main) }.map_err(KernelError::FailedToJoinMainThread)?;
 
     Ok(())
 }
@@ -541,31 +541,6 @@ fn discord_presence(param: &Param) -> Result<(), DiscordPresenceError> {
     Ok(())
 }
 
-#[cfg(unix)]
-fn join_thread(thr: OsThread) -> Result<(), std::io::Error> {
-    let err = unsafe { libc::pthread_join(thr, std::ptr::null_mut()) };
-
-    if err != 0 {
-        Err(std::io::Error::from_raw_os_error(err))
-    } else {
-        Ok(())
-    }
-}
-
-#[cfg(windows)]
-fn join_thread(thr: OsThread) -> Result<(), std::io::Error> {
-    use windows_sys::Win32::Foundation::{CloseHandle, WAIT_OBJECT_0};
-    use windows_sys::Win32::System::Threading::{WaitForSingleObject, INFINITE};
-
-    if unsafe { WaitForSingleObject(thr, INFINITE) } != WAIT_OBJECT_0 {
-        return Err(std::io::Error::last_os_error());
-    }
-
-    assert_ne!(unsafe { CloseHandle(thr) }, 0);
-
-    Ok(())
-}
-
 #[derive(Debug, Error)]
 enum DiscordPresenceError {
     #[error("failed to create Discord IPC")]
@@ -645,5 +620,5 @@ enum KernelError {
     FailedToCreateMainThread(#[from] SpawnError),
 
     #[error("failed to join with main thread")]
-    FailedToJoinMainThread(#[source] std::io::Error),
+    FailedToJoinMainThread(#[source] llt::JoinError),
 }
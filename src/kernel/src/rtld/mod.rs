@@ -108,7 +108,7 @@ impl RuntimeLinker {
         }
 
         // Get base address.
-        let base = if elf.ty() == FileType::ET_SCE_DYNEXEC {
+        let base = if elf.ty().is_dynamic_exec() {
             0x400000
         } else {
             0
@@ -222,7 +222,7 @@ impl RuntimeLinker {
         };
 
         // Check image type.
-        if elf.ty() != FileType::ET_SCE_DYNAMIC {
+        if !elf.ty().is_shared_object() {
             return Err(LoadError::InvalidElf);
         }
 
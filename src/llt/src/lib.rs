@@ -58,6 +58,67 @@ where
     result
 }
 
+/// Same as [`spawn()`] but also set the name of the thread to `name` so it show up on the debugger
+/// and the profiler. The name may be truncated depend on the platform (e.g. 15 bytes on Linux).
+///
+/// # Safety
+/// See [`spawn()`] for the requirements.
+pub unsafe fn spawn_named<F>(
+    stack: *mut u8,
+    stack_size: usize,
+    name: impl Into<String>,
+    mut entry: F,
+) -> Result<OsThread, SpawnError>
+where
+    F: FnMut() + Send + 'static,
+{
+    let name = name.into();
+
+    spawn(stack, stack_size, move || {
+        set_name(&name);
+        entry();
+    })
+}
+
+/// Wait for `thr` to exit. The thread object will be released after this function return so it
+/// must not be used after this.
+///
+/// # Safety
+/// `thr` must be a valid thread object that does not joined or detached.
+pub unsafe fn join(thr: OsThread) -> Result<(), JoinError> {
+    #[cfg(unix)]
+    let result = unix::join(thr);
+    #[cfg(windows)]
+    let result = {
+        use windows_sys::Win32::Foundation::{CloseHandle, WAIT_OBJECT_0};
+        use windows_sys::Win32::System::Threading::{WaitForSingleObject, INFINITE};
+
+        if WaitForSingleObject(thr, INFINITE) != WAIT_OBJECT_0 {
+            Err(JoinError::WaitFailed(std::io::Error::last_os_error()))
+        } else {
+            assert_ne!(CloseHandle(thr), 0);
+            Ok(())
+        }
+    };
+
+    result
+}
+
+/// Set the name of the calling thread. Any error will be ignored since the name is for diagnostic
+/// purpose only.
+fn set_name(name: &str) {
+    #[cfg(unix)]
+    unix::set_name(name);
+    #[cfg(windows)]
+    {
+        use windows_sys::Win32::System::Threading::{GetCurrentThread, SetThreadDescription};
+
+        let name: Vec<u16> = name.encode_utf16().chain(std::iter::once(0)).collect();
+
+        unsafe { SetThreadDescription(GetCurrentThread(), name.as_ptr()) };
+    }
+}
+
 #[cfg(unix)]
 extern "C" fn invoker<T>(arg: *mut c_void) -> *mut c_void
 where
@@ -182,6 +243,13 @@ pub enum SpawnError {
     CreateThreadFailed(#[source] std::io::Error),
 }
 
+/// Represents an error when [`join()`] fails.
+#[derive(Debug, Error)]
+pub enum JoinError {
+    #[error("cannot wait for the thread")]
+    WaitFailed(#[source] std::io::Error),
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(windows)]
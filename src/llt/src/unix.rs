@@ -1,7 +1,7 @@
-use crate::SpawnError;
+use crate::{JoinError, SpawnError};
 use libc::{
     c_int, c_void, pthread_attr_destroy, pthread_attr_init, pthread_attr_t, pthread_create,
-    pthread_t,
+    pthread_join, pthread_t,
 };
 use std::io::Error;
 use std::mem::MaybeUninit;
@@ -38,6 +38,40 @@ pub unsafe fn spawn(
     }
 }
 
+pub unsafe fn join(thr: pthread_t) -> Result<(), JoinError> {
+    let err = pthread_join(thr, std::ptr::null_mut());
+
+    if err != 0 {
+        Err(JoinError::WaitFailed(Error::from_raw_os_error(err)))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub fn set_name(name: &str) {
+    // Linux limit the name to 16 bytes including the NUL.
+    let mut len = name.len().min(15);
+
+    while !name.is_char_boundary(len) {
+        len -= 1;
+    }
+
+    if let Ok(name) = std::ffi::CString::new(&name[..len]) {
+        unsafe { libc::pthread_setname_np(libc::pthread_self(), name.as_ptr()) };
+    }
+}
+
+#[cfg(target_os = "macos")]
+pub fn set_name(name: &str) {
+    if let Ok(name) = std::ffi::CString::new(name) {
+        unsafe { libc::pthread_setname_np(name.as_ptr()) };
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+pub fn set_name(_: &str) {}
+
 struct PthreadAttr(pthread_attr_t);
 
 impl PthreadAttr {
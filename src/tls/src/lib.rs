@@ -39,6 +39,37 @@ impl<T> Tls<T> {
         }
     }
 
+    /// Returns the value for the current thread or initialize it with `f` if it is not set.
+    pub fn get_or(&self, f: impl FnOnce() -> T) -> Local<'_, T> {
+        let storage = self.storage();
+        let value = unsafe { Self::get_raw(storage) };
+
+        if !value.is_null() {
+            return Local::new(value);
+        }
+
+        // Construct the value. We need to check the storage again because f might set it.
+        let value = Box::new(f());
+        let prev = unsafe { Self::get_raw(storage) };
+
+        if !prev.is_null() {
+            return Local::new(prev);
+        }
+
+        // Set the value.
+        let value = Box::into_raw(value);
+        unsafe { Self::set_raw(storage, value) };
+
+        Local::new(value)
+    }
+
+    /// Invoke `f` with the value for the current thread. Returns [`None`] if the value is not set.
+    ///
+    /// The value is borrowed the same way as [`Local`] so `f` can call this method again.
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> Option<R> {
+        self.get().map(|v| f(&v))
+    }
+
     pub fn set(&self, value: T) -> Option<T> {
         // T has been forced to outlive us by PhantomData.
         let storage = self.storage();